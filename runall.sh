@@ -122,3 +122,17 @@ do
     done
     
 done
+
+# the spmat utility self-tests live behind the -U flag of transpose_matrix
+echo;echo; echo XXXXXXXXXXXX spmat self-tests XXXXXXXXXXXXXXX
+for i in `seq 0 2 4`
+do
+    nodes=$((2**i))
+    cores=$(($nodes*$cores_per_node))
+    cmd="$LAUNCHER -n $cores $BALEDIR/build_$PLATFORM/apps/transpose_matrix -U"
+    eval "$cmd"
+    if [ $? -ne 0 ]; then
+        echo "ERROR! $cmd"
+        exit 1
+    fi
+done
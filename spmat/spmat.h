@@ -127,6 +127,8 @@ sparsemat_t * transpose_matrix_exstack2(sparsemat_t * A, int64_t buf_cnt);
 sparsemat_t * transpose_matrix_exstack(sparsemat_t * A, int64_t buf_cnt);
 sparsemat_t * transpose_matrix_agi(sparsemat_t * A);
 
+int64_t edge_cut_agi(sparsemat_t * A, SHARED int64_t * part);
+
 int64_t write_sparse_matrix_agi( char * datadir, sparsemat_t * mat);
 int64_t write_sparse_matrix_exstack( char * datadir, sparsemat_t * mat, int64_t buf_cnt);
 
@@ -162,6 +164,12 @@ sparsemat_t * gen_star(int64_t m, int mode);
 sparsemat_t * gen_local_mat_from_stars(int64_t M, int64_t * m, int mode);
 
 int compare_matrix(sparsemat_t *lmat, sparsemat_t *rmat);
+int check_matrix(sparsemat_t *A, int64_t tidy);
+int64_t count_parallel_nonzeros(sparsemat_t *A);
+double matrix_density(sparsemat_t *A);
+int is_sparse(sparsemat_t *A, double threshold);
+uint64_t matrix_fingerprint(sparsemat_t *A);
+double ownership_balance(sparsemat_t *A, int64_t * minlnnz, int64_t * maxlnnz);
 sparsemat_t * copy_matrix(sparsemat_t *srcmat);
 int sort_nonzeros( sparsemat_t *mat);
 